@@ -0,0 +1,61 @@
+use hyper::{Headers, Response, StatusCode};
+use std::io::Error as IoError;
+
+#[derive(Debug)]
+pub enum AzureError {
+    UnexpectedHttpResult { expected: StatusCode, received: StatusCode, body: String },
+    HyperError(::hyper::Error),
+    IoError(IoError),
+    TimerError(::tokio::timer::Error),
+    ParsingError(String),
+    GenericError(String),
+}
+
+impl From<::hyper::Error> for AzureError {
+    fn from(e: ::hyper::Error) -> AzureError {
+        AzureError::HyperError(e)
+    }
+}
+
+impl From<IoError> for AzureError {
+    fn from(e: IoError) -> AzureError {
+        AzureError::IoError(e)
+    }
+}
+
+impl From<::tokio::timer::Error> for AzureError {
+    fn from(e: ::tokio::timer::Error) -> AzureError {
+        AzureError::TimerError(e)
+    }
+}
+
+pub fn check_status_extract_headers_and_body(
+    resp: Response,
+    expected_status: StatusCode,
+) -> impl (::futures::Future<Item = (Headers, Vec<u8>), Error = AzureError>) {
+    let status = resp.status();
+    let headers = resp.headers().clone();
+
+    resp.body()
+        .concat2()
+        .from_err()
+        .and_then(move |body| {
+            if status == expected_status {
+                Ok((headers, body.to_vec()))
+            } else {
+                Err(AzureError::UnexpectedHttpResult {
+                    expected: expected_status,
+                    received: status,
+                    body: String::from_utf8_lossy(&body).into_owned(),
+                })
+            }
+        })
+}
+
+pub fn check_status_extract_headers_and_body_as_string(
+    resp: Response,
+    expected_status: StatusCode,
+) -> impl (::futures::Future<Item = (Headers, String), Error = AzureError>) {
+    check_status_extract_headers_and_body(resp, expected_status)
+        .and_then(|(headers, body)| Ok((headers, String::from_utf8_lossy(&body).into_owned())))
+}
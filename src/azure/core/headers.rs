@@ -0,0 +1,6 @@
+pub static LEASE_ACTION: &str = "x-ms-lease-action";
+pub static LEASE_DURATION: &str = "x-ms-lease-duration";
+pub static LEASE_BREAK_PERIOD: &str = "x-ms-lease-break-period";
+pub static PROPOSED_LEASE_ID: &str = "x-ms-proposed-lease-id";
+pub static LEASE_ID: &str = "x-ms-lease-id";
+pub static LEASE_TIME: &str = "x-ms-lease-time";
@@ -0,0 +1,32 @@
+#[derive(Debug, Clone)]
+pub struct IncompleteVector<T> {
+    next_marker: Option<String>,
+    vector: Vec<T>,
+}
+
+impl<T> IncompleteVector<T> {
+    pub fn new(next_marker: Option<String>, vector: Vec<T>) -> IncompleteVector<T> {
+        IncompleteVector { next_marker, vector }
+    }
+
+    pub fn next_marker(&self) -> &Option<String> {
+        &self.next_marker
+    }
+
+    pub fn vector(&self) -> &[T] {
+        &self.vector
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.vector
+    }
+}
+
+impl<T> IntoIterator for IncompleteVector<T> {
+    type Item = T;
+    type IntoIter = ::std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vector.into_iter()
+    }
+}
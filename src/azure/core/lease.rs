@@ -0,0 +1,27 @@
+use std::fmt;
+use uuid::Uuid;
+
+/// Identifies a lease on a container or blob, as returned by `AcquireLeaseBuilder` or supplied
+/// by the caller as a proposed id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeaseId(Uuid);
+
+impl LeaseId {
+    pub fn new(uuid: Uuid) -> LeaseId {
+        LeaseId(uuid)
+    }
+}
+
+impl fmt::Display for LeaseId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ::std::str::FromStr for LeaseId {
+    type Err = ::uuid::ParseError;
+
+    fn from_str(s: &str) -> Result<LeaseId, Self::Err> {
+        Ok(LeaseId(Uuid::parse_str(s)?))
+    }
+}
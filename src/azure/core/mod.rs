@@ -0,0 +1,118 @@
+pub mod errors;
+pub mod headers;
+pub mod incompletevector;
+pub mod lease;
+pub mod parsing;
+pub mod retry_policy;
+pub mod token_credential;
+
+use self::errors::AzureError;
+use azure::storage::client::Client;
+use hyper::{Headers, Request};
+
+/// Marker types used to encode, at the type level, which required fields a builder has already
+/// had set (`Yes`) or still needs (`No`).
+pub trait ToAssign {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Yes;
+#[derive(Debug, Clone, Copy)]
+pub struct No;
+
+impl ToAssign for Yes {}
+impl ToAssign for No {}
+
+pub trait ClientRequired<'a> {
+    fn client(&self) -> &'a Client;
+}
+
+pub trait ContainerNameRequired<'a> {
+    fn container_name(&self) -> &'a str;
+}
+
+pub trait ContainerNameSupport<'a> {
+    type O;
+
+    fn with_container_name(self, container_name: &'a str) -> Self::O;
+}
+
+pub trait ClientRequestIdOption<'a> {
+    fn client_request_id(&self) -> Option<&'a str>;
+
+    fn add_header(&self, request: &mut Request) {
+        if let Some(client_request_id) = self.client_request_id() {
+            request.headers_mut().set_raw("x-ms-client-request-id", client_request_id.to_owned());
+        }
+    }
+}
+
+pub trait ClientRequestIdSupport<'a> {
+    type O;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O;
+}
+
+pub trait TimeoutOption {
+    fn timeout(&self) -> Option<u64>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.timeout().map(|timeout| format!("timeout={}", timeout))
+    }
+}
+
+pub trait TimeoutSupport {
+    type O;
+
+    fn with_timeout(self, timeout: u64) -> Self::O;
+}
+
+pub trait PrefixOption<'a> {
+    fn prefix(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.prefix().map(|prefix| format!("prefix={}", prefix))
+    }
+}
+
+pub trait PrefixSupport<'a> {
+    type O;
+
+    fn with_prefix(self, prefix: &'a str) -> Self::O;
+}
+
+pub trait NextMarkerOption<'a> {
+    fn next_marker(&self) -> Option<&'a str>;
+
+    fn to_uri_parameter(&self) -> Option<String> {
+        self.next_marker().map(|next_marker| format!("marker={}", next_marker))
+    }
+}
+
+pub trait NextMarkerSupport<'a> {
+    type O;
+
+    fn with_next_marker(self, next_marker: &'a str) -> Self::O;
+}
+
+pub trait LeaseIdRequired<'a> {
+    fn lease_id(&self) -> &'a self::lease::LeaseId;
+
+    fn add_header(&self, request: &mut Request) {
+        request.headers_mut().set_raw(self::headers::LEASE_ID, self.lease_id().to_string());
+    }
+}
+
+pub trait LeaseIdSupport<'a> {
+    type O;
+
+    fn with_lease_id(self, lease_id: &'a self::lease::LeaseId) -> Self::O;
+}
+
+pub fn request_id_from_headers(headers: &Headers) -> Result<String, AzureError> {
+    headers
+        .get_raw("x-ms-request-id")
+        .and_then(|raw| raw.one())
+        .and_then(|raw| ::std::str::from_utf8(raw).ok())
+        .map(|s| s.to_owned())
+        .ok_or_else(|| AzureError::GenericError("missing x-ms-request-id header".to_owned()))
+}
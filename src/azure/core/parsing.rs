@@ -0,0 +1,51 @@
+use azure::core::errors::AzureError;
+use std::str::FromStr;
+use xml::Element;
+
+/// Walks `elem` through each name in `path`, returning the matched children at the final
+/// level. When `required` is true, a missing intermediate level is an error rather than an
+/// empty result.
+pub fn traverse<'a>(elem: &'a Element, path: &[&str], required: bool) -> Result<Vec<&'a Element>, AzureError> {
+    let mut current = vec![elem];
+
+    for (depth, name) in path.iter().enumerate() {
+        let mut next = Vec::new();
+
+        for node in &current {
+            next.extend(node.children.iter().filter_map(|child| match child {
+                ::xml::Xml::ElementNode(ref e) if e.name == *name => Some(e),
+                _ => None,
+            }));
+        }
+
+        if next.is_empty() && required && depth + 1 < path.len() {
+            return Err(AzureError::ParsingError(format!("expected element {} not found", name)));
+        }
+
+        current = next;
+    }
+
+    Ok(current)
+}
+
+pub fn cast_optional<T>(elem: &Element, path: &[&str]) -> Result<Option<T>, AzureError>
+where
+    T: FromStr,
+{
+    let found = traverse(elem, path, false)?;
+
+    match found.into_iter().next() {
+        None => Ok(None),
+        Some(e) => match e.content_str().parse::<T>() {
+            Ok(v) => Ok(Some(v)),
+            Err(_) => Err(AzureError::ParsingError(format!("could not parse {:?}", path))),
+        },
+    }
+}
+
+pub fn cast<T>(elem: &Element, path: &[&str]) -> Result<T, AzureError>
+where
+    T: FromStr,
+{
+    cast_optional(elem, path)?.ok_or_else(|| AzureError::ParsingError(format!("missing required element {:?}", path)))
+}
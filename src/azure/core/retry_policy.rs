@@ -0,0 +1,108 @@
+use hyper::StatusCode;
+use rand::Rng;
+use std::time::Duration;
+
+/// Controls how `Client::perform_request` retries transient failures.
+///
+/// Retries use exponential backoff with full jitter: the delay for attempt `n` is
+/// `random(0, min(max_delay, base_delay * 2^n))`, unless the response carries a `Retry-After`
+/// header, in which case that value is used verbatim.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, base_delay: Duration, max_delay: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_retries,
+            base_delay,
+            max_delay,
+        }
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// The delay to wait after attempt `attempt` (0-indexed, so 0 is the initial request) has
+    /// failed and before the next one, given an optional server-provided `Retry-After` duration.
+    pub fn backoff(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+
+        let cap = self.base_delay.checked_mul(1 << attempt.min(30)).unwrap_or(self.max_delay);
+        let cap = if cap > self.max_delay { self.max_delay } else { cap };
+
+        let cap_millis = cap.as_secs() * 1000 + u64::from(cap.subsec_millis());
+        let jittered_millis = if cap_millis == 0 { 0 } else { ::rand::thread_rng().gen_range(0, cap_millis + 1) };
+
+        Duration::from_millis(jittered_millis)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy::new(3, Duration::from_millis(100), Duration::from_secs(30))
+    }
+}
+
+/// Whether a response status code represents a transient failure worth retrying.
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::INTERNAL_SERVER_ERROR || status == StatusCode::SERVICE_UNAVAILABLE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after_overrides_backoff_verbatim() {
+        let policy = RetryPolicy::new(5, Duration::from_millis(100), Duration::from_secs(30));
+        let retry_after = Duration::from_secs(7);
+
+        assert_eq!(policy.backoff(0, Some(retry_after)), retry_after);
+        assert_eq!(policy.backoff(4, Some(retry_after)), retry_after);
+    }
+
+    #[test]
+    fn backoff_is_bounded_by_max_delay() {
+        let policy = RetryPolicy::new(10, Duration::from_millis(100), Duration::from_secs(1));
+
+        for attempt in 0..10 {
+            let delay = policy.backoff(attempt, None);
+            assert!(delay <= Duration::from_secs(1), "attempt {} produced {:?}", attempt, delay);
+        }
+    }
+
+    #[test]
+    fn backoff_grows_with_attempt_before_capping() {
+        let policy = RetryPolicy::new(10, Duration::from_millis(100), Duration::from_secs(30));
+
+        // attempt 0's cap is base_delay itself (100ms); attempt 3's cap is base_delay * 8 (800ms),
+        // well below max_delay. Attempt 0 never exceeds its cap, attempt 3 stays within its larger
+        // cap, and over 20 jittered samples attempt 3 goes past attempt 0's cap.
+        let mut longest_attempt_3 = Duration::from_millis(0);
+        for _ in 0..20 {
+            assert!(policy.backoff(0, None) <= Duration::from_millis(100));
+
+            let delay = policy.backoff(3, None);
+            assert!(delay <= Duration::from_millis(800));
+            if delay > longest_attempt_3 {
+                longest_attempt_3 = delay;
+            }
+        }
+        assert!(longest_attempt_3 > Duration::from_millis(100));
+    }
+
+    #[test]
+    fn backoff_does_not_overflow_on_large_attempt_numbers() {
+        let policy = RetryPolicy::new(1000, Duration::from_millis(100), Duration::from_secs(30));
+
+        let delay = policy.backoff(1000, None);
+        assert!(delay <= Duration::from_secs(30));
+    }
+}
@@ -0,0 +1,30 @@
+use azure::core::errors::AzureError;
+use chrono::{DateTime, Utc};
+use futures::future::Future;
+
+/// A bearer token obtained from an Azure Active Directory credential, along with its expiry.
+#[derive(Debug, Clone)]
+pub struct AccessToken {
+    token: String,
+    expires_on: DateTime<Utc>,
+}
+
+impl AccessToken {
+    pub fn new(token: String, expires_on: DateTime<Utc>) -> AccessToken {
+        AccessToken { token, expires_on }
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn expires_on(&self) -> DateTime<Utc> {
+        self.expires_on
+    }
+}
+
+/// Source of Azure Active Directory bearer tokens, used by `Client` as an alternative to
+/// shared-key authentication.
+pub trait TokenCredential: Send + Sync {
+    fn get_token(&self, scopes: &[&str]) -> Box<Future<Item = AccessToken, Error = AzureError> + Send>;
+}
@@ -0,0 +1,458 @@
+use azure::core::errors::AzureError;
+use azure::core::retry_policy::{is_retryable_status, RetryPolicy};
+use azure::core::token_credential::{AccessToken, TokenCredential};
+use base64;
+use chrono::Utc;
+use futures::future::{done, Future};
+use hmac::{Hmac, Mac};
+use hyper::client::HttpConnector;
+use hyper::{Client as HyperClient, Method, Request, Response};
+use hyper_tls::HttpsConnector;
+use sha2::Sha256;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::timer::Delay;
+
+const STORAGE_TOKEN_SCOPE: &str = "https://storage.azure.com/.default";
+// refresh a cached token a bit before it actually expires to avoid racing the clock
+const TOKEN_REFRESH_SKEW_SECONDS: i64 = 5 * 60;
+const STORAGE_API_VERSION: &str = "2018-03-28";
+
+type AuthAttacher = Arc<Fn(Request) -> Box<Future<Item = Request, Error = AzureError> + Send> + Send + Sync>;
+
+enum Auth {
+    SharedKey(String),
+    Token {
+        credential: Arc<TokenCredential>,
+        cached: Arc<Mutex<Option<AccessToken>>>,
+    },
+}
+
+pub struct Client {
+    account: String,
+    auth: Auth,
+    hyper_client: HyperClient<HttpsConnector<HttpConnector>>,
+    retry_policy: RetryPolicy,
+}
+
+impl Client {
+    pub fn new(account: &str, access_key: &str) -> Client {
+        Client {
+            account: account.to_owned(),
+            auth: Auth::SharedKey(access_key.to_owned()),
+            hyper_client: HyperClient::builder().build(HttpsConnector::new(4).unwrap()),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    pub fn with_token_credential(account: &str, credential: Box<TokenCredential>) -> Client {
+        Client {
+            account: account.to_owned(),
+            auth: Auth::Token {
+                credential: Arc::from(credential),
+                cached: Arc::new(Mutex::new(None)),
+            },
+            hyper_client: HyperClient::builder().build(HttpsConnector::new(4).unwrap()),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Overrides the default `RetryPolicy` (3 retries, 100ms base delay, 30s max delay) used by
+    /// `perform_request` for retryable operations.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn account(&self) -> &str {
+        &self.account
+    }
+
+    pub fn list_containers(&self) -> ::azure::storage::container::requests::ListBuilder {
+        ::azure::storage::container::requests::list_builder::ListBuilder::new(self)
+    }
+
+    pub fn acquire_lease(&self) -> ::azure::storage::container::requests::acquire_lease_builder::AcquireLeaseBuilder<::azure::core::No> {
+        ::azure::storage::container::requests::acquire_lease_builder::AcquireLeaseBuilder::new(self)
+    }
+
+    pub fn renew_lease(
+        &self,
+    ) -> ::azure::storage::container::requests::renew_lease_builder::RenewLeaseBuilder<::azure::core::No, ::azure::core::No> {
+        ::azure::storage::container::requests::renew_lease_builder::RenewLeaseBuilder::new(self)
+    }
+
+    pub fn release_lease(
+        &self,
+    ) -> ::azure::storage::container::requests::release_lease_builder::ReleaseLeaseBuilder<::azure::core::No, ::azure::core::No> {
+        ::azure::storage::container::requests::release_lease_builder::ReleaseLeaseBuilder::new(self)
+    }
+
+    pub fn break_lease(&self) -> ::azure::storage::container::requests::break_lease_builder::BreakLeaseBuilder<::azure::core::No> {
+        ::azure::storage::container::requests::break_lease_builder::BreakLeaseBuilder::new(self)
+    }
+
+    pub fn change_lease(
+        &self,
+    ) -> ::azure::storage::container::requests::change_lease_builder::ChangeLeaseBuilder<
+        ::azure::core::No,
+        ::azure::core::No,
+        ::azure::core::No,
+    > {
+        ::azure::storage::container::requests::change_lease_builder::ChangeLeaseBuilder::new(self)
+    }
+
+    fn auth_attacher(&self) -> AuthAttacher {
+        match self.auth {
+            Auth::SharedKey(ref access_key) => {
+                let access_key = access_key.clone();
+                let account = self.account.clone();
+                Arc::new(move |mut request: Request| -> Box<Future<Item = Request, Error = AzureError> + Send> {
+                    Box::new(done(sign_with_shared_key(&account, &access_key, &mut request).map(|_| request)))
+                })
+            }
+            Auth::Token { ref credential, ref cached } => {
+                let credential = credential.clone();
+                let cached = cached.clone();
+                Arc::new(move |mut request: Request| -> Box<Future<Item = Request, Error = AzureError> + Send> {
+                    Box::new(bearer_token(&credential, &cached).map(move |bearer| {
+                        request.headers_mut().set_raw("Authorization", format!("Bearer {}", bearer));
+                        request
+                    }))
+                })
+            }
+        }
+    }
+
+    /// Builds and issues an HTTP request against Storage. `f` is given the chance to add
+    /// operation-specific headers before the shared-key signature (or bearer token) is attached.
+    ///
+    /// When `retry` is `true` the request is retried on HTTP 429/500/503 and connection-level
+    /// errors using `self.retry_policy`, honoring a `Retry-After` response header when present.
+    /// GET-based listing is always retried; lease renewal retries only when opted in on its
+    /// builder (`with_retry()`); acquire, release, break and change never retry.
+    pub fn perform_request<'f, F>(
+        &self,
+        uri: &str,
+        method: &Method,
+        f: F,
+        request_body: Option<&[u8]>,
+        retry: bool,
+    ) -> Result<Box<Future<Item = Response, Error = AzureError> + Send + 'f>, AzureError>
+    where
+        F: Fn(&mut Request) + Send + Sync + 'f,
+    {
+        let uri: ::hyper::Uri = uri.parse().map_err(|_| AzureError::GenericError("invalid uri".to_owned()))?;
+        let retry_policy = if retry { Some(self.retry_policy.clone()) } else { None };
+
+        Ok(perform_attempt(
+            self.hyper_client.clone(),
+            uri,
+            method.clone(),
+            Arc::new(f),
+            request_body.map(|b| b.to_vec()),
+            self.auth_attacher(),
+            retry_policy,
+            0,
+        ))
+    }
+}
+
+/// Signs `request` in place with Azure Storage's Shared Key scheme: stamps `x-ms-date` /
+/// `x-ms-version`, builds the canonicalized-headers and canonicalized-resource strings, HMAC-
+/// SHA256s the result with the base64-decoded account key, and sets the `Authorization` header.
+fn sign_with_shared_key(account: &str, access_key: &str, request: &mut Request) -> Result<(), AzureError> {
+    request.headers_mut().set_raw("x-ms-date", Utc::now().format("%a, %d %b %Y %H:%M:%S GMT").to_string());
+    request.headers_mut().set_raw("x-ms-version", STORAGE_API_VERSION);
+
+    let content_length = request
+        .headers()
+        .get::<::hyper::header::ContentLength>()
+        .map(|cl| cl.0)
+        .filter(|len| *len > 0)
+        .map(|len| len.to_string())
+        .unwrap_or_default();
+
+    let string_to_sign = format!(
+        "{method}\n\n\n{content_length}\n\n\n\n\n\n\n\n\n{canonicalized_headers}{canonicalized_resource}",
+        method = request.method(),
+        content_length = content_length,
+        canonicalized_headers = canonicalized_headers(request),
+        canonicalized_resource = canonicalized_resource(account, request.uri()),
+    );
+
+    let key = base64::decode(access_key).map_err(|_| AzureError::GenericError("shared key is not valid base64".to_owned()))?;
+    let mut mac =
+        Hmac::<Sha256>::new_varkey(&key).map_err(|_| AzureError::GenericError("shared key has an invalid length".to_owned()))?;
+    mac.input(string_to_sign.as_bytes());
+    let signature = base64::encode(&mac.result().code());
+
+    request.headers_mut().set_raw("Authorization", format!("SharedKey {}:{}", account, signature));
+
+    Ok(())
+}
+
+fn canonicalized_headers(request: &Request) -> String {
+    let mut x_ms_headers: Vec<(String, String)> = request
+        .headers()
+        .iter()
+        .filter(|h| h.name().to_lowercase().starts_with("x-ms-"))
+        .map(|h| (h.name().to_lowercase(), h.value_string()))
+        .collect();
+
+    x_ms_headers.sort_by(|a, b| a.0.cmp(&b.0));
+
+    x_ms_headers.into_iter().map(|(name, value)| format!("{}:{}\n", name, value)).collect()
+}
+
+fn canonicalized_resource(account: &str, uri: &::hyper::Uri) -> String {
+    let mut canonicalized = format!("/{}{}", account, uri.path());
+
+    let mut query_pairs: Vec<(String, String)> = ::url::form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    query_pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (name, value) in query_pairs {
+        canonicalized.push_str(&format!("\n{}:{}", name.to_lowercase(), value));
+    }
+
+    canonicalized
+}
+
+fn bearer_token(
+    credential: &Arc<TokenCredential>,
+    cached: &Arc<Mutex<Option<AccessToken>>>,
+) -> Box<Future<Item = String, Error = AzureError> + Send> {
+    if let Some(ref token) = *cached.lock().unwrap() {
+        if token.expires_on() - Utc::now() > ::chrono::Duration::seconds(TOKEN_REFRESH_SKEW_SECONDS) {
+            return Box::new(done(Ok(token.token().to_owned())));
+        }
+    }
+
+    let cached = cached.clone();
+
+    Box::new(credential.get_token(&[STORAGE_TOKEN_SCOPE]).map(move |token| {
+        let bearer = token.token().to_owned();
+        *cached.lock().unwrap() = Some(token);
+        bearer
+    }))
+}
+
+fn retry_after_header(response: &Response) -> Option<Duration> {
+    response
+        .headers()
+        .get_raw("Retry-After")
+        .and_then(|raw| raw.one())
+        .and_then(|raw| ::std::str::from_utf8(raw).ok())
+        .and_then(|s| s.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+fn perform_attempt<'f>(
+    hyper_client: HyperClient<HttpsConnector<HttpConnector>>,
+    uri: ::hyper::Uri,
+    method: Method,
+    f: Arc<Fn(&mut Request) + Send + Sync + 'f>,
+    body: Option<Vec<u8>>,
+    auth_attacher: AuthAttacher,
+    retry_policy: Option<RetryPolicy>,
+    attempt_no: u32,
+) -> Box<Future<Item = Response, Error = AzureError> + Send + 'f> {
+    // `Request::new` and `auth_attacher` both run fresh on every call, including the recursive
+    // calls below for each retry: a retried request gets its own `x-ms-date` and is re-signed
+    // from scratch rather than replaying the first attempt's (by-then stale) Authorization header.
+    let mut request = Request::new(method.clone(), uri.clone());
+    if let Some(ref body) = body {
+        request.set_body(body.clone());
+    }
+    f(&mut request);
+
+    let retry_hyper_client = hyper_client.clone();
+    let retry_uri = uri.clone();
+    let retry_method = method.clone();
+    let retry_f = f.clone();
+    let retry_body = body.clone();
+    let retry_auth_attacher = auth_attacher.clone();
+    let retry_policy_for_retry = retry_policy.clone();
+
+    Box::new(
+        auth_attacher(request)
+            .and_then(move |request| hyper_client.request(request).from_err())
+            .then(move |result| -> Box<Future<Item = Response, Error = AzureError> + Send + 'f> {
+                let retry_policy = match retry_policy_for_retry {
+                    Some(ref policy) if attempt_no < policy.max_retries() => policy.clone(),
+                    _ => return Box::new(done(result)),
+                };
+
+                let retry_after = match result {
+                    Ok(ref response) if is_retryable_status(response.status()) => retry_after_header(response),
+                    Ok(_) => return Box::new(done(result)),
+                    Err(_) => None,
+                };
+
+                let delay = retry_policy.backoff(attempt_no, retry_after);
+
+                Box::new(Delay::new(Instant::now() + delay).from_err().and_then(move |_| {
+                    perform_attempt(
+                        retry_hyper_client,
+                        retry_uri,
+                        retry_method,
+                        retry_f,
+                        retry_body,
+                        retry_auth_attacher,
+                        Some(retry_policy),
+                        attempt_no + 1,
+                    )
+                }))
+            }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+    use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+    fn request_with_headers(headers: &[(&str, &str)]) -> Request {
+        let mut request = Request::new(Method::GET, "https://myaccount.blob.core.windows.net/mycontainer?comp=list&restype=container"
+            .parse()
+            .unwrap());
+        for (name, value) in headers {
+            request.headers_mut().set_raw(name.to_string(), value.to_string());
+        }
+        request
+    }
+
+    #[test]
+    fn auth_attacher_resigns_independently_on_every_invocation() {
+        // `perform_attempt` calls `auth_attacher`'s closure fresh on every retry attempt; this
+        // confirms that produces an independent, current signature rather than replaying a
+        // stale one, which is the behavior the retry path depends on.
+        let client = Client::new("myaccount", &base64::encode(b"0123456789abcdef0123456789abcdef"));
+        let attacher = client.auth_attacher();
+
+        let first = attacher(request_with_headers(&[])).wait().unwrap();
+        let first_auth = first.headers().get_raw("Authorization").and_then(|raw| raw.one()).unwrap().to_vec();
+
+        let mut retried_request = Request::new(
+            Method::GET,
+            "https://myaccount.blob.core.windows.net/mycontainer?comp=list&restype=container&marker=page-2"
+                .parse()
+                .unwrap(),
+        );
+        retried_request.headers_mut().set_raw("x-ms-client-request-id", "retry-attempt");
+        let second = attacher(retried_request).wait().unwrap();
+        let second_auth = second.headers().get_raw("Authorization").and_then(|raw| raw.one()).unwrap().to_vec();
+
+        // same account key, but a different canonicalized resource (the retried request's
+        // marker) means a different signature, proving it was recomputed rather than reused
+        assert_ne!(first_auth, second_auth);
+        assert!(second.headers().get_raw("x-ms-date").is_some());
+    }
+
+    #[test]
+    fn canonicalized_headers_filters_and_sorts_x_ms_headers() {
+        let request = request_with_headers(&[
+            ("x-ms-version", "2018-03-28"),
+            ("x-ms-date", "Mon, 27 Jan 2020 08:49:37 GMT"),
+            ("content-length", "0"),
+        ]);
+
+        assert_eq!(
+            canonicalized_headers(&request),
+            "x-ms-date:Mon, 27 Jan 2020 08:49:37 GMT\nx-ms-version:2018-03-28\n"
+        );
+    }
+
+    #[test]
+    fn canonicalized_resource_sorts_query_parameters() {
+        let uri: ::hyper::Uri = "https://myaccount.blob.core.windows.net/mycontainer?restype=container&comp=list"
+            .parse()
+            .unwrap();
+
+        assert_eq!(canonicalized_resource("myaccount", &uri), "/myaccount/mycontainer\ncomp:list\nrestype:container");
+    }
+
+    #[test]
+    fn canonicalized_resource_with_no_query_is_just_the_path() {
+        let uri: ::hyper::Uri = "https://myaccount.blob.core.windows.net/mycontainer".parse().unwrap();
+
+        assert_eq!(canonicalized_resource("myaccount", &uri), "/myaccount/mycontainer");
+    }
+
+    #[test]
+    fn sign_with_shared_key_sets_a_shared_key_authorization_header() {
+        let mut request = request_with_headers(&[]);
+        let access_key = base64::encode(b"0123456789abcdef0123456789abcdef");
+
+        sign_with_shared_key("myaccount", &access_key, &mut request).unwrap();
+
+        let auth = request.headers().get_raw("Authorization").and_then(|raw| raw.one()).unwrap();
+        let auth = ::std::str::from_utf8(auth).unwrap();
+
+        assert!(auth.starts_with("SharedKey myaccount:"));
+        let signature = auth.trim_start_matches("SharedKey myaccount:");
+        assert!(base64::decode(signature).is_ok());
+        assert!(request.headers().get_raw("x-ms-version").is_some());
+        assert!(request.headers().get_raw("x-ms-date").is_some());
+    }
+
+    #[test]
+    fn sign_with_shared_key_rejects_non_base64_key() {
+        let mut request = request_with_headers(&[]);
+
+        assert!(sign_with_shared_key("myaccount", "not valid base64!!", &mut request).is_err());
+    }
+
+    struct CountingCredential {
+        calls: Arc<AtomicUsize>,
+        token: String,
+        expires_on: ::chrono::DateTime<Utc>,
+    }
+
+    impl TokenCredential for CountingCredential {
+        fn get_token(&self, _scopes: &[&str]) -> Box<Future<Item = AccessToken, Error = AzureError> + Send> {
+            self.calls.fetch_add(1, AtomicOrdering::SeqCst);
+            Box::new(done(Ok(AccessToken::new(self.token.clone(), self.expires_on))))
+        }
+    }
+
+    #[test]
+    fn bearer_token_reuses_unexpired_cached_token() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let credential: Arc<TokenCredential> = Arc::new(CountingCredential {
+            calls: calls.clone(),
+            token: "fresh-token".to_owned(),
+            expires_on: Utc::now() + ChronoDuration::hours(1),
+        });
+        let cached = Arc::new(Mutex::new(None));
+
+        let first = bearer_token(&credential, &cached).wait().unwrap();
+        let second = bearer_token(&credential, &cached).wait().unwrap();
+
+        assert_eq!(first, "fresh-token");
+        assert_eq!(second, "fresh-token");
+        assert_eq!(calls.load(AtomicOrdering::SeqCst), 1);
+    }
+
+    #[test]
+    fn bearer_token_refetches_when_cached_token_is_within_refresh_skew() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let credential: Arc<TokenCredential> = Arc::new(CountingCredential {
+            calls: calls.clone(),
+            token: "refreshed-token".to_owned(),
+            expires_on: Utc::now() + ChronoDuration::hours(1),
+        });
+        let cached = Arc::new(Mutex::new(Some(AccessToken::new(
+            "stale-token".to_owned(),
+            Utc::now() + ChronoDuration::seconds(30),
+        ))));
+
+        let refreshed = bearer_token(&credential, &cached).wait().unwrap();
+
+        assert_eq!(refreshed, "refreshed-token");
+        assert_eq!(calls.load(AtomicOrdering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,188 @@
+pub mod requests;
+pub mod responses;
+
+use azure::core::errors::AzureError;
+use azure::core::parsing::{cast, traverse};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::str::FromStr;
+use xml::Element;
+
+/// Mirrors Azure's `x-ms-lease-status` / `LeaseStatus` value set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseStatus {
+    Locked,
+    Unlocked,
+}
+
+impl FromStr for LeaseStatus {
+    type Err = AzureError;
+
+    fn from_str(s: &str) -> Result<LeaseStatus, AzureError> {
+        match s {
+            "locked" => Ok(LeaseStatus::Locked),
+            "unlocked" => Ok(LeaseStatus::Unlocked),
+            _ => Err(AzureError::ParsingError(format!("unknown lease status {:?}", s))),
+        }
+    }
+}
+
+/// Mirrors Azure's `x-ms-lease-state` / `LeaseState` value set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseState {
+    Available,
+    Leased,
+    Expired,
+    Breaking,
+    Broken,
+}
+
+impl FromStr for LeaseState {
+    type Err = AzureError;
+
+    fn from_str(s: &str) -> Result<LeaseState, AzureError> {
+        match s {
+            "available" => Ok(LeaseState::Available),
+            "leased" => Ok(LeaseState::Leased),
+            "expired" => Ok(LeaseState::Expired),
+            "breaking" => Ok(LeaseState::Breaking),
+            "broken" => Ok(LeaseState::Broken),
+            _ => Err(AzureError::ParsingError(format!("unknown lease state {:?}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerProperties {
+    pub last_modified: DateTime<Utc>,
+    pub e_tag: String,
+    pub lease_status: LeaseStatus,
+    pub lease_state: LeaseState,
+}
+
+impl ContainerProperties {
+    fn parse(elem: &Element) -> Result<ContainerProperties, AzureError> {
+        let last_modified: String = cast(elem, &["Last-Modified"])?;
+        let last_modified = DateTime::parse_from_rfc2822(&last_modified)
+            .map_err(|e| AzureError::ParsingError(format!("invalid Last-Modified {:?}: {}", last_modified, e)))?
+            .with_timezone(&Utc);
+
+        Ok(ContainerProperties {
+            last_modified,
+            e_tag: cast(elem, &["Etag"])?,
+            lease_status: cast(elem, &["LeaseStatus"])?,
+            lease_state: cast(elem, &["LeaseState"])?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Container {
+    pub name: String,
+    pub properties: ContainerProperties,
+    /// Populated from the per-container `x-ms-meta-*` metadata when the listing request was
+    /// built with `include_metadata()`; empty otherwise.
+    pub metadata: HashMap<String, String>,
+}
+
+impl Container {
+    pub(crate) fn parse(elem: &Element) -> Result<Container, AzureError> {
+        let name = cast(elem, &["Name"])?;
+
+        let properties = traverse(elem, &["Properties"], true)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| AzureError::ParsingError("missing Properties element".to_owned()))
+            .and_then(ContainerProperties::parse)?;
+
+        let metadata = match traverse(elem, &["Metadata"], false)?.into_iter().next() {
+            None => HashMap::new(),
+            Some(metadata_elem) => metadata_elem
+                .children
+                .iter()
+                .filter_map(|child| match child {
+                    ::xml::Xml::ElementNode(ref e) => Some(e),
+                    _ => None,
+                })
+                .map(|e| Ok((e.name.clone(), e.content_str())))
+                .collect::<Result<HashMap<String, String>, AzureError>>()?,
+        };
+
+        Ok(Container { name, properties, metadata })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_properties_and_metadata() {
+        let xml = r#"
+            <Container>
+                <Name>my-container</Name>
+                <Properties>
+                    <Last-Modified>Mon, 27 Jan 2020 08:49:37 GMT</Last-Modified>
+                    <Etag>"0x8D7824B3A8C9F2E"</Etag>
+                    <LeaseStatus>locked</LeaseStatus>
+                    <LeaseState>leased</LeaseState>
+                </Properties>
+                <Metadata>
+                    <env>prod</env>
+                    <team>storage</team>
+                </Metadata>
+            </Container>
+        "#;
+        let elem: Element = xml.parse().unwrap();
+
+        let container = Container::parse(&elem).unwrap();
+
+        assert_eq!(container.name, "my-container");
+        assert_eq!(container.properties.e_tag, "\"0x8D7824B3A8C9F2E\"");
+        assert_eq!(container.properties.lease_status, LeaseStatus::Locked);
+        assert_eq!(container.properties.lease_state, LeaseState::Leased);
+        assert_eq!(container.properties.last_modified.to_rfc3339(), "2020-01-27T08:49:37+00:00");
+        assert_eq!(container.metadata.get("env"), Some(&"prod".to_owned()));
+        assert_eq!(container.metadata.get("team"), Some(&"storage".to_owned()));
+    }
+
+    #[test]
+    fn defaults_metadata_to_empty_when_absent() {
+        let xml = r#"
+            <Container>
+                <Name>my-container</Name>
+                <Properties>
+                    <Last-Modified>Mon, 27 Jan 2020 08:49:37 GMT</Last-Modified>
+                    <Etag>"0x8D7824B3A8C9F2E"</Etag>
+                    <LeaseStatus>unlocked</LeaseStatus>
+                    <LeaseState>available</LeaseState>
+                </Properties>
+            </Container>
+        "#;
+        let elem: Element = xml.parse().unwrap();
+
+        let container = Container::parse(&elem).unwrap();
+
+        assert!(container.metadata.is_empty());
+        assert_eq!(container.properties.lease_status, LeaseStatus::Unlocked);
+        assert_eq!(container.properties.lease_state, LeaseState::Available);
+    }
+
+    #[test]
+    fn rejects_unknown_lease_status() {
+        let xml = r#"
+            <Container>
+                <Name>my-container</Name>
+                <Properties>
+                    <Last-Modified>Mon, 27 Jan 2020 08:49:37 GMT</Last-Modified>
+                    <Etag>"0x8D7824B3A8C9F2E"</Etag>
+                    <LeaseStatus>bogus</LeaseStatus>
+                    <LeaseState>available</LeaseState>
+                </Properties>
+            </Container>
+        "#;
+        let elem: Element = xml.parse().unwrap();
+
+        assert!(Container::parse(&elem).is_err());
+    }
+}
@@ -0,0 +1,210 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::{LEASE_ACTION, LEASE_DURATION, PROPOSED_LEASE_ID};
+use azure::core::lease::LeaseId;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::client::Client;
+use azure::storage::container::responses::AcquireLeaseResponse;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+/// A lease duration of -1 means infinite; otherwise it must be between 15 and 60 seconds.
+const LEASE_DURATION_INFINITE: i8 = -1;
+
+#[derive(Debug, Clone)]
+pub struct AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    container_name: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+    lease_duration: i8,
+    proposed_lease_id: Option<&'a LeaseId>,
+}
+
+impl<'a> AcquireLeaseBuilder<'a, No> {
+    pub(crate) fn new(client: &'a Client) -> AcquireLeaseBuilder<'a, No> {
+        AcquireLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            client_request_id: None,
+            timeout: None,
+            lease_duration: LEASE_DURATION_INFINITE,
+            proposed_lease_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequired<'a> for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ContainerNameRequired<'a> for AcquireLeaseBuilder<'a, Yes> {
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdOption<'a> for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutOption for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet> ContainerNameSupport<'a> for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = AcquireLeaseBuilder<'a, Yes>;
+
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        AcquireLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: Some(container_name),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdSupport<'a> for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = AcquireLeaseBuilder<'a, ContainerNameSet>;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        AcquireLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutSupport for AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = AcquireLeaseBuilder<'a, ContainerNameSet>;
+
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        AcquireLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+            lease_duration: self.lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet> AcquireLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    pub fn lease_duration(&self) -> i8 {
+        self.lease_duration
+    }
+
+    /// 15 to 60 seconds, or -1 for an infinite lease.
+    pub fn with_lease_duration(self, lease_duration: i8) -> Self {
+        AcquireLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_duration,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+
+    pub fn proposed_lease_id(&self) -> Option<&'a LeaseId> {
+        self.proposed_lease_id
+    }
+
+    pub fn with_proposed_lease_id(self, proposed_lease_id: &'a LeaseId) -> Self {
+        AcquireLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_duration: self.lease_duration,
+            proposed_lease_id: Some(proposed_lease_id),
+        }
+    }
+}
+
+impl<'a> AcquireLeaseBuilder<'a, Yes> {
+    pub fn finalize(self) -> impl Future<Item = AcquireLeaseResponse, Error = AzureError> {
+        let mut uri = format!(
+            "https://{}.blob.core.windows.net/{}?comp=lease&restype=container",
+            self.client().account(),
+            self.container_name()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+                request.header(LEASE_ACTION, "acquire");
+                request.header(LEASE_DURATION, self.lease_duration().to_string());
+
+                if let Some(proposed_lease_id) = self.proposed_lease_id() {
+                    request.header(PROPOSED_LEASE_ID, proposed_lease_id.to_string());
+                }
+            },
+            Some(&[]),
+            // acquire is not idempotent: a retried PUT could acquire a lease the caller no
+            // longer expects to hold if the first attempt actually succeeded server-side
+            false,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::CREATED))
+            .and_then(|(headers, _body)| done(AcquireLeaseResponse::from_headers(&headers)))
+    }
+}
@@ -0,0 +1,181 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::{LEASE_ACTION, LEASE_BREAK_PERIOD};
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, TimeoutOption,
+    TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::client::Client;
+use azure::storage::container::responses::BreakLeaseResponse;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    container_name: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+    // 0 to 60 seconds; `None` lets the service choose a default
+    break_period: Option<u64>,
+}
+
+impl<'a> BreakLeaseBuilder<'a, No> {
+    pub(crate) fn new(client: &'a Client) -> BreakLeaseBuilder<'a, No> {
+        BreakLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            container_name: None,
+            client_request_id: None,
+            timeout: None,
+            break_period: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequired<'a> for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a> ContainerNameRequired<'a> for BreakLeaseBuilder<'a, Yes> {
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdOption<'a> for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutOption for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet> ContainerNameSupport<'a> for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakLeaseBuilder<'a, Yes>;
+
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        BreakLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: Some(container_name),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            break_period: self.break_period,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> ClientRequestIdSupport<'a> for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakLeaseBuilder<'a, ContainerNameSet>;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        BreakLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+            break_period: self.break_period,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet> TimeoutSupport for BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    type O = BreakLeaseBuilder<'a, ContainerNameSet>;
+
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        BreakLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+            break_period: self.break_period,
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet> BreakLeaseBuilder<'a, ContainerNameSet>
+where
+    ContainerNameSet: ToAssign,
+{
+    pub fn break_period(&self) -> Option<u64> {
+        self.break_period
+    }
+
+    pub fn with_break_period(self, break_period: u64) -> Self {
+        BreakLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            break_period: Some(break_period),
+        }
+    }
+}
+
+impl<'a> BreakLeaseBuilder<'a, Yes> {
+    pub fn finalize(self) -> impl Future<Item = BreakLeaseResponse, Error = AzureError> {
+        let mut uri = format!(
+            "https://{}.blob.core.windows.net/{}?comp=lease&restype=container",
+            self.client().account(),
+            self.container_name()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+                request.header(LEASE_ACTION, "break");
+
+                if let Some(break_period) = self.break_period() {
+                    request.header(LEASE_BREAK_PERIOD, break_period.to_string());
+                }
+            },
+            Some(&[]),
+            false,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::ACCEPTED))
+            .and_then(|(headers, _body)| done(BreakLeaseResponse::from_headers(&headers)))
+    }
+}
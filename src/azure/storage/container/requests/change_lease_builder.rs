@@ -0,0 +1,260 @@
+use azure::core::errors::{check_status_extract_headers_and_body, AzureError};
+use azure::core::headers::{LEASE_ACTION, PROPOSED_LEASE_ID};
+use azure::core::lease::LeaseId;
+use azure::core::{
+    ClientRequestIdOption, ClientRequestIdSupport, ClientRequired, ContainerNameRequired, ContainerNameSupport, LeaseIdRequired,
+    LeaseIdSupport, TimeoutOption, TimeoutSupport,
+};
+use azure::core::{No, ToAssign, Yes};
+use azure::storage::client::Client;
+use azure::storage::container::responses::ChangeLeaseResponse;
+use futures::future::{done, Future};
+use hyper::{Method, StatusCode};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+pub struct ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    client: &'a Client,
+    p_container_name: PhantomData<ContainerNameSet>,
+    p_lease_id: PhantomData<LeaseIdSet>,
+    p_proposed_lease_id: PhantomData<ProposedLeaseIdSet>,
+    container_name: Option<&'a str>,
+    client_request_id: Option<&'a str>,
+    timeout: Option<u64>,
+    lease_id: Option<&'a LeaseId>,
+    proposed_lease_id: Option<&'a LeaseId>,
+}
+
+impl<'a> ChangeLeaseBuilder<'a, No, No, No> {
+    pub(crate) fn new(client: &'a Client) -> ChangeLeaseBuilder<'a, No, No, No> {
+        ChangeLeaseBuilder {
+            client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: None,
+            client_request_id: None,
+            timeout: None,
+            lease_id: None,
+            proposed_lease_id: None,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequired<'a>
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    fn client(&self) -> &'a Client {
+        self.client
+    }
+}
+
+impl<'a, LeaseIdSet, ProposedLeaseIdSet> ContainerNameRequired<'a> for ChangeLeaseBuilder<'a, Yes, LeaseIdSet, ProposedLeaseIdSet>
+where
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    fn container_name(&self) -> &'a str {
+        self.container_name.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequestIdOption<'a>
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    fn client_request_id(&self) -> Option<&'a str> {
+        self.client_request_id
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> TimeoutOption
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    fn timeout(&self) -> Option<u64> {
+        self.timeout
+    }
+}
+
+impl<'a, ContainerNameSet, ProposedLeaseIdSet> LeaseIdRequired<'a> for ChangeLeaseBuilder<'a, ContainerNameSet, Yes, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    fn lease_id(&self) -> &'a LeaseId {
+        self.lease_id.unwrap()
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ContainerNameSupport<'a>
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeLeaseBuilder<'a, Yes, LeaseIdSet, ProposedLeaseIdSet>;
+
+    fn with_container_name(self, container_name: &'a str) -> Self::O {
+        ChangeLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: Some(container_name),
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ClientRequestIdSupport<'a>
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>;
+
+    fn with_client_request_id(self, client_request_id: &'a str) -> Self::O {
+        ChangeLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: Some(client_request_id),
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> TimeoutSupport
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>;
+
+    fn with_timeout(self, timeout: u64) -> Self::O {
+        ChangeLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: Some(timeout),
+            lease_id: self.lease_id,
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> LeaseIdSupport<'a>
+    for ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    type O = ChangeLeaseBuilder<'a, ContainerNameSet, Yes, ProposedLeaseIdSet>;
+
+    fn with_lease_id(self, lease_id: &'a LeaseId) -> Self::O {
+        ChangeLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_id: Some(lease_id),
+            proposed_lease_id: self.proposed_lease_id,
+        }
+    }
+}
+
+// methods callable regardless
+impl<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet> ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, ProposedLeaseIdSet>
+where
+    ContainerNameSet: ToAssign,
+    LeaseIdSet: ToAssign,
+    ProposedLeaseIdSet: ToAssign,
+{
+    pub fn proposed_lease_id(&self) -> Option<&'a LeaseId> {
+        self.proposed_lease_id
+    }
+
+    pub fn with_proposed_lease_id(
+        self,
+        proposed_lease_id: &'a LeaseId,
+    ) -> ChangeLeaseBuilder<'a, ContainerNameSet, LeaseIdSet, Yes> {
+        ChangeLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            p_proposed_lease_id: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            proposed_lease_id: Some(proposed_lease_id),
+        }
+    }
+}
+
+impl<'a> ChangeLeaseBuilder<'a, Yes, Yes, Yes> {
+    pub fn finalize(self) -> impl Future<Item = ChangeLeaseResponse, Error = AzureError> {
+        let mut uri = format!(
+            "https://{}.blob.core.windows.net/{}?comp=lease&restype=container",
+            self.client().account(),
+            self.container_name()
+        );
+
+        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+            uri = format!("{}&{}", uri, nm);
+        }
+
+        let req = self.client().perform_request(
+            &uri,
+            &Method::PUT,
+            |ref mut request| {
+                ClientRequestIdOption::add_header(&self, request);
+                LeaseIdRequired::add_header(&self, request);
+                request.header(LEASE_ACTION, "change");
+                request.header(PROPOSED_LEASE_ID, self.proposed_lease_id().unwrap().to_string());
+            },
+            Some(&[]),
+            false,
+        );
+
+        done(req)
+            .from_err()
+            .and_then(move |future_response| check_status_extract_headers_and_body(future_response, StatusCode::OK))
+            .and_then(|(headers, _body)| done(ChangeLeaseResponse::from_headers(&headers)))
+    }
+}
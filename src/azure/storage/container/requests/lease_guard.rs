@@ -0,0 +1,190 @@
+use azure::core::errors::AzureError;
+use azure::core::lease::LeaseId;
+use azure::core::{ContainerNameSupport, LeaseIdSupport};
+use azure::storage::client::Client;
+use futures::future::Future;
+use futures::Stream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::timer::Interval;
+
+/// RAII guard over an acquired container lease. Renews the lease in the background at roughly
+/// half its duration and releases it when dropped, so callers get mutual exclusion without
+/// hand-rolling a renew timer.
+///
+/// `Drop` only releases the lease on a best-effort basis: it requires a running Tokio executor
+/// to spawn the release request onto, and silently skips the release if none is available (e.g.
+/// the guard outlives `tokio::run`). Callers who need the release to actually happen, or who may
+/// drop the guard outside a running runtime, should call `release()` explicitly instead.
+pub struct LeaseGuard {
+    client: Arc<Client>,
+    container_name: String,
+    lease_id: LeaseId,
+    stop: Arc<AtomicBool>,
+    released: bool,
+}
+
+impl LeaseGuard {
+    /// Acquires a lease on `container_name` with the given `lease_duration` (15-60 seconds, or
+    /// -1 for infinite) and starts a background task renewing it at half that interval. An
+    /// infinite lease is renewed on a fixed best-effort schedule instead, since there is no
+    /// duration to halve.
+    pub fn acquire(client: Arc<Client>, container_name: String, lease_duration: i8) -> impl Future<Item = LeaseGuard, Error = AzureError> {
+        let acquire_client = client.clone();
+        let container_name_for_acquire = container_name.clone();
+
+        // the builder only borrows `client` for the duration of this call; the future it
+        // returns owns everything it needs to run to completion
+        acquire_client
+            .acquire_lease()
+            .with_container_name(&container_name_for_acquire)
+            .with_lease_duration(lease_duration)
+            .finalize()
+            .map(move |response| {
+                let stop = Arc::new(AtomicBool::new(false));
+
+                spawn_renewal(
+                    client.clone(),
+                    container_name.clone(),
+                    response.lease_id,
+                    lease_duration,
+                    stop.clone(),
+                );
+
+                LeaseGuard {
+                    client,
+                    container_name,
+                    lease_id: response.lease_id,
+                    stop,
+                    released: false,
+                }
+            })
+    }
+
+    pub fn lease_id(&self) -> LeaseId {
+        self.lease_id
+    }
+
+    /// Stops the background renewal and releases the lease, returning the future that drives the
+    /// release request so the caller can await (or simply observe) its outcome. Prefer this over
+    /// relying on `Drop`, which is best-effort and silently skips the release outside a running
+    /// Tokio executor.
+    pub fn release(mut self) -> impl Future<Item = (), Error = AzureError> {
+        self.stop.store(true, Ordering::Relaxed);
+        self.released = true;
+
+        self.client
+            .release_lease()
+            .with_container_name(&self.container_name)
+            .with_lease_id(&self.lease_id)
+            .finalize()
+            .map(|_| ())
+    }
+}
+
+fn renewal_period(lease_duration: i8) -> Duration {
+    if lease_duration <= 0 {
+        // infinite lease: there is no duration to halve, so pick a conservative fixed cadence
+        Duration::from_secs(30)
+    } else {
+        Duration::from_secs(lease_duration as u64 / 2)
+    }
+}
+
+fn spawn_renewal(client: Arc<Client>, container_name: String, lease_id: LeaseId, lease_duration: i8, stop: Arc<AtomicBool>) {
+    let period = renewal_period(lease_duration);
+
+    let renewal = Interval::new(Instant::now() + period, period)
+        .from_err::<AzureError>()
+        .take_while(move |_| Ok(!stop.load(Ordering::Relaxed)))
+        .for_each(move |_| {
+            client
+                .renew_lease()
+                .with_container_name(&container_name)
+                .with_lease_id(&lease_id)
+                .finalize()
+                .map(|_| ())
+        })
+        .map_err(|_| ());
+
+    ::tokio::spawn(renewal);
+}
+
+impl Drop for LeaseGuard {
+    fn drop(&mut self) {
+        if self.released {
+            return;
+        }
+
+        self.stop.store(true, Ordering::Relaxed);
+
+        // `tokio::spawn` panics with no active executor (e.g. the guard outliving `tokio::run`);
+        // only spawn the best-effort release when one is actually running. Callers who need the
+        // release to happen unconditionally should use `release()` instead.
+        if ::tokio::executor::DefaultExecutor::current().status().is_err() {
+            return;
+        }
+
+        let release = self
+            .client
+            .release_lease()
+            .with_container_name(&self.container_name)
+            .with_lease_id(&self.lease_id)
+            .finalize()
+            .map(|_| ())
+            .map_err(|_| ());
+
+        ::tokio::spawn(release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_client() -> Client {
+        Client::new("myaccount", &::base64::encode(b"0123456789abcdef0123456789abcdef"))
+    }
+
+    #[test]
+    fn renewal_period_halves_a_finite_lease_duration() {
+        assert_eq!(renewal_period(40), Duration::from_secs(20));
+    }
+
+    #[test]
+    fn renewal_period_uses_a_fixed_cadence_for_infinite_leases() {
+        assert_eq!(renewal_period(-1), Duration::from_secs(30));
+        assert_eq!(renewal_period(0), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn drop_does_not_panic_without_a_running_executor() {
+        // plain #[test] functions run on an ordinary thread with no Tokio executor, which is
+        // exactly the situation that used to make `Drop::drop` panic inside `tokio::spawn`.
+        let guard = LeaseGuard {
+            client: Arc::new(test_client()),
+            container_name: "mycontainer".to_owned(),
+            lease_id: LeaseId::new(::uuid::Uuid::nil()),
+            stop: Arc::new(AtomicBool::new(false)),
+            released: false,
+        };
+
+        drop(guard);
+    }
+
+    #[test]
+    fn drop_after_explicit_release_does_not_release_twice() {
+        let guard = LeaseGuard {
+            client: Arc::new(test_client()),
+            container_name: "mycontainer".to_owned(),
+            lease_id: LeaseId::new(::uuid::Uuid::nil()),
+            stop: Arc::new(AtomicBool::new(false)),
+            released: true,
+        };
+
+        // with `released` already set, `Drop::drop` must return immediately rather than build
+        // (and potentially spawn) a second release request.
+        drop(guard);
+    }
+}
@@ -9,6 +9,7 @@ use azure::storage::client::Client;
 use azure::storage::container::responses::ListContainersResponse;
 use azure::storage::container::Container;
 use futures::future::{done, Future};
+use futures::stream::{self, Stream};
 use hyper::{Method, StatusCode};
 use xml::Element;
 
@@ -78,6 +79,14 @@ impl<'a> ListBuilder<'a> {
     }
 
     pub fn finalize(self) -> impl Future<Item = ListContainersResponse, Error = AzureError> {
+        let next_marker = self.next_marker();
+        self.finalize_with_marker(next_marker)
+    }
+
+    /// Like `finalize`, but takes the `NextMarker` to request explicitly instead of reading it
+    /// off `self`. `stream()` uses this to page through a marker it owns, rather than one
+    /// borrowed for `'a`.
+    fn finalize_with_marker(&self, next_marker: Option<&str>) -> impl Future<Item = ListContainersResponse, Error = AzureError> {
         let mut uri = format!(
             "https://{}.blob.core.windows.net?comp=list&maxresults={}",
             self.client().account(),
@@ -88,25 +97,31 @@ impl<'a> ListBuilder<'a> {
             uri = format!("{}&include=metadata", uri);
         }
 
-        if let Some(nm) = PrefixOption::to_uri_parameter(&self) {
+        if let Some(nm) = PrefixOption::to_uri_parameter(self) {
             uri = format!("{}&{}", uri, nm);
         }
 
-        if let Some(nm) = NextMarkerOption::to_uri_parameter(&self) {
-            uri = format!("{}&{}", uri, nm);
+        if let Some(nm) = next_marker {
+            uri = format!("{}&marker={}", uri, nm);
         }
 
-        if let Some(nm) = TimeoutOption::to_uri_parameter(&self) {
+        if let Some(nm) = TimeoutOption::to_uri_parameter(self) {
             uri = format!("{}&{}", uri, nm);
         }
 
+        // own a copy of the header-setting state so the request closure doesn't have to borrow
+        // `self`, which only lives for the duration of this call
+        let request_options = self.clone();
+
         let req = self.client().perform_request(
             &uri,
             &Method::GET,
-            |ref mut request| {
-                ClientRequestIdOption::add_header(&self, request);
+            move |ref mut request| {
+                ClientRequestIdOption::add_header(&request_options, request);
             },
             None,
+            // listing is a GET and therefore always safe to retry
+            true,
         );
 
         done(req).from_err().and_then(move |future_response| {
@@ -120,6 +135,38 @@ impl<'a> ListBuilder<'a> {
             })
         })
     }
+
+    /// Returns a `Stream` that yields every `Container` across all pages, transparently
+    /// re-issuing the request with the previous page's `NextMarker` until the server reports
+    /// there is nothing left to list. The marker is carried as an owned `String` in the unfold
+    /// state rather than borrowed, so paging through many (or unboundedly many) pages doesn't
+    /// leak memory.
+    pub fn stream(self) -> impl Stream<Item = Container, Error = AzureError> {
+        stream::unfold(Some((self, None)), move |state| match state {
+            None => None,
+            Some((builder, marker)) => Some(
+                builder
+                    .finalize_with_marker(marker.as_ref().map(String::as_str))
+                    .map(move |response| {
+                        let next_marker = response.incomplete_vector.next_marker().clone();
+                        let items = response.incomplete_vector.into_vec();
+
+                        (stream::iter_ok(items), next_page_state(builder, next_marker))
+                    }),
+            ),
+        })
+        .flatten()
+    }
+}
+
+/// Decides whether `stream()` should keep paging: a missing or empty `NextMarker` means the
+/// server has nothing left to list, ending the stream rather than looping forever on an empty
+/// marker.
+fn next_page_state<'a>(builder: ListBuilder<'a>, next_marker: Option<String>) -> Option<(ListBuilder<'a>, Option<String>)> {
+    match next_marker {
+        Some(ref nm) if !nm.is_empty() => Some((builder, Some(nm.clone()))),
+        _ => None,
+    }
 }
 
 impl<'a> PrefixOption<'a> for ListBuilder<'a> {
@@ -230,3 +277,78 @@ fn incomplete_vector_from_response(body: &str) -> Result<IncompleteVector<Contai
 
     Ok(IncompleteVector::new(next_marker, v))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_response(next_marker_elem: &str) -> String {
+        format!(
+            r#"
+            <EnumerationResults>
+                <Containers>
+                    <Container>
+                        <Name>container-one</Name>
+                        <Properties>
+                            <Last-Modified>Mon, 27 Jan 2020 08:49:37 GMT</Last-Modified>
+                            <Etag>"0x1"</Etag>
+                            <LeaseStatus>unlocked</LeaseStatus>
+                            <LeaseState>available</LeaseState>
+                        </Properties>
+                    </Container>
+                    <Container>
+                        <Name>container-two</Name>
+                        <Properties>
+                            <Last-Modified>Mon, 27 Jan 2020 08:49:37 GMT</Last-Modified>
+                            <Etag>"0x2"</Etag>
+                            <LeaseStatus>unlocked</LeaseStatus>
+                            <LeaseState>available</LeaseState>
+                        </Properties>
+                    </Container>
+                </Containers>
+                {}
+            </EnumerationResults>
+            "#,
+            next_marker_elem
+        )
+    }
+
+    #[test]
+    fn parses_every_container_and_a_non_empty_next_marker() {
+        let body = list_response("<NextMarker>page-2-marker</NextMarker>");
+
+        let incomplete_vector = incomplete_vector_from_response(&body).unwrap();
+
+        assert_eq!(incomplete_vector.next_marker(), &Some("page-2-marker".to_owned()));
+        let names: Vec<_> = incomplete_vector.into_vec().into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["container-one".to_owned(), "container-two".to_owned()]);
+    }
+
+    #[test]
+    fn treats_an_empty_next_marker_as_the_last_page() {
+        let body = list_response("<NextMarker />");
+
+        let incomplete_vector = incomplete_vector_from_response(&body).unwrap();
+
+        assert_eq!(incomplete_vector.next_marker(), &None);
+    }
+
+    #[test]
+    fn next_page_state_continues_on_non_empty_marker() {
+        let client = Client::new("myaccount", &::base64::encode(b"0123456789abcdef0123456789abcdef"));
+        let builder = ListBuilder::new(&client);
+
+        let state = next_page_state(builder, Some("page-2-marker".to_owned()));
+
+        assert!(state.is_some());
+        assert_eq!(state.unwrap().1, Some("page-2-marker".to_owned()));
+    }
+
+    #[test]
+    fn next_page_state_stops_on_missing_or_empty_marker() {
+        let client = Client::new("myaccount", &::base64::encode(b"0123456789abcdef0123456789abcdef"));
+
+        assert!(next_page_state(ListBuilder::new(&client), None).is_none());
+        assert!(next_page_state(ListBuilder::new(&client), Some(String::new())).is_none());
+    }
+}
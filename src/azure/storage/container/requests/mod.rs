@@ -0,0 +1,15 @@
+pub mod acquire_lease_builder;
+pub mod break_lease_builder;
+pub mod change_lease_builder;
+pub mod lease_guard;
+pub mod list_builder;
+pub mod release_lease_builder;
+pub mod renew_lease_builder;
+
+pub use self::acquire_lease_builder::AcquireLeaseBuilder;
+pub use self::break_lease_builder::BreakLeaseBuilder;
+pub use self::change_lease_builder::ChangeLeaseBuilder;
+pub use self::lease_guard::LeaseGuard;
+pub use self::list_builder::ListBuilder;
+pub use self::release_lease_builder::ReleaseLeaseBuilder;
+pub use self::renew_lease_builder::RenewLeaseBuilder;
@@ -25,6 +25,7 @@ where
     client_request_id: Option<&'a str>,
     timeout: Option<u64>,
     lease_id: Option<&'a LeaseId>,
+    retry: bool,
 }
 
 impl<'a> RenewLeaseBuilder<'a, No, No> {
@@ -37,6 +38,7 @@ impl<'a> RenewLeaseBuilder<'a, No, No> {
             lease_id: None,
             client_request_id: None,
             timeout: None,
+            retry: false,
         }
     }
 }
@@ -105,6 +107,7 @@ where
             client_request_id: self.client_request_id,
             timeout: self.timeout,
             lease_id: self.lease_id,
+            retry: self.retry,
         }
     }
 }
@@ -125,6 +128,7 @@ where
             client_request_id: Some(client_request_id),
             timeout: self.timeout,
             lease_id: self.lease_id,
+            retry: self.retry,
         }
     }
 }
@@ -145,6 +149,7 @@ where
             client_request_id: self.client_request_id,
             timeout: Some(timeout),
             lease_id: self.lease_id,
+            retry: self.retry,
         }
     }
 }
@@ -165,6 +170,7 @@ where
             client_request_id: self.client_request_id,
             timeout: self.timeout,
             lease_id: Some(lease_id),
+            retry: self.retry,
         }
     }
 }
@@ -174,7 +180,26 @@ impl<'a, ContainerNameSet, LeaseIdSet> RenewLeaseBuilder<'a, ContainerNameSet, L
 where
     ContainerNameSet: ToAssign,
     LeaseIdSet: ToAssign,
-{}
+{
+    /// Renew is idempotent, but retrying is opt-in since callers may want explicit control over
+    /// re-issuing a PUT. Off by default.
+    pub fn is_retry_enabled(&self) -> bool {
+        self.retry
+    }
+
+    pub fn with_retry(self) -> Self {
+        RenewLeaseBuilder {
+            client: self.client,
+            p_container_name: PhantomData {},
+            p_lease_id: PhantomData {},
+            container_name: self.container_name,
+            client_request_id: self.client_request_id,
+            timeout: self.timeout,
+            lease_id: self.lease_id,
+            retry: true,
+        }
+    }
+}
 
 impl<'a> RenewLeaseBuilder<'a, Yes, Yes> {
     pub fn finalize(self) -> impl Future<Item = RenewLeaseResponse, Error = AzureError> {
@@ -188,6 +213,7 @@ impl<'a> RenewLeaseBuilder<'a, Yes, Yes> {
             uri = format!("{}&{}", uri, nm);
         }
 
+        let retry = self.retry;
         let req = self.client().perform_request(
             &uri,
             &Method::PUT,
@@ -197,6 +223,7 @@ impl<'a> RenewLeaseBuilder<'a, Yes, Yes> {
                 request.header(LEASE_ACTION, "renew");
             },
             Some(&[]),
+            retry,
         );
 
         done(req)
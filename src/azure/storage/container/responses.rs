@@ -0,0 +1,105 @@
+use azure::core::errors::AzureError;
+use azure::core::headers::{LEASE_ID, LEASE_TIME};
+use azure::core::incompletevector::IncompleteVector;
+use azure::core::lease::LeaseId;
+use azure::core::request_id_from_headers;
+use azure::storage::container::Container;
+use hyper::Headers;
+
+#[derive(Debug, Clone)]
+pub struct ListContainersResponse {
+    pub incomplete_vector: IncompleteVector<Container>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RenewLeaseResponse {
+    pub request_id: String,
+}
+
+impl RenewLeaseResponse {
+    pub fn from_headers(headers: &Headers) -> Result<RenewLeaseResponse, AzureError> {
+        Ok(RenewLeaseResponse {
+            request_id: request_id_from_headers(headers)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AcquireLeaseResponse {
+    pub lease_id: LeaseId,
+    pub request_id: String,
+}
+
+impl AcquireLeaseResponse {
+    pub fn from_headers(headers: &Headers) -> Result<AcquireLeaseResponse, AzureError> {
+        let lease_id = headers
+            .get_raw(LEASE_ID)
+            .and_then(|raw| raw.one())
+            .and_then(|raw| ::std::str::from_utf8(raw).ok())
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| AzureError::GenericError(format!("missing or invalid {} header", LEASE_ID)))?;
+
+        Ok(AcquireLeaseResponse {
+            lease_id,
+            request_id: request_id_from_headers(headers)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReleaseLeaseResponse {
+    pub request_id: String,
+}
+
+impl ReleaseLeaseResponse {
+    pub fn from_headers(headers: &Headers) -> Result<ReleaseLeaseResponse, AzureError> {
+        Ok(ReleaseLeaseResponse {
+            request_id: request_id_from_headers(headers)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BreakLeaseResponse {
+    pub lease_time: u64,
+    pub request_id: String,
+}
+
+impl BreakLeaseResponse {
+    pub fn from_headers(headers: &Headers) -> Result<BreakLeaseResponse, AzureError> {
+        let lease_time = headers
+            .get_raw(LEASE_TIME)
+            .and_then(|raw| raw.one())
+            .and_then(|raw| ::std::str::from_utf8(raw).ok())
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| AzureError::GenericError(format!("missing or invalid {} header", LEASE_TIME)))?;
+
+        Ok(BreakLeaseResponse {
+            lease_time,
+            request_id: request_id_from_headers(headers)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ChangeLeaseResponse {
+    pub lease_id: LeaseId,
+    pub request_id: String,
+}
+
+impl ChangeLeaseResponse {
+    pub fn from_headers(headers: &Headers) -> Result<ChangeLeaseResponse, AzureError> {
+        let lease_id = headers
+            .get_raw(LEASE_ID)
+            .and_then(|raw| raw.one())
+            .and_then(|raw| ::std::str::from_utf8(raw).ok())
+            .and_then(|s| s.parse().ok())
+            .ok_or_else(|| AzureError::GenericError(format!("missing or invalid {} header", LEASE_ID)))?;
+
+        Ok(ChangeLeaseResponse {
+            lease_id,
+            request_id: request_id_from_headers(headers)?,
+        })
+    }
+}